@@ -0,0 +1,179 @@
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::{parse_macro_input, Ident, LitStr, Token, Type};
+
+mod kw {
+    syn::custom_keyword!(has);
+    syn::custom_keyword!(with);
+}
+
+struct FieldDef {
+    ident: Ident,
+    ty: Type,
+    key: LitStr,
+    modifiers: Vec<String>,
+}
+
+struct ComponentDef {
+    name: Ident,
+    component_key: LitStr,
+    component_modifiers: Vec<String>,
+    fields: Vec<FieldDef>,
+}
+
+fn parse_modifiers(input: ParseStream) -> syn::Result<Vec<String>> {
+    let mut modifiers = Vec::new();
+    if input.peek(kw::with) {
+        input.parse::<kw::with>()?;
+        while input.peek(LitStr) {
+            let lit: LitStr = input.parse()?;
+            modifiers.push(lit.value());
+        }
+    }
+    Ok(modifiers)
+}
+
+impl Parse for ComponentDef {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<Ident>()?; // `name`
+        input.parse::<Token![=]>()?;
+        let name: Ident = input.parse()?;
+        input.parse::<Token![for]>()?;
+        let component_key: LitStr = input.parse()?;
+        let component_modifiers = parse_modifiers(input)?;
+        input.parse::<Token![;]>()?;
+
+        let mut fields = Vec::new();
+        while !input.is_empty() {
+            let ident: Ident = input.parse()?;
+            input.parse::<kw::has>()?;
+            let ty: Type = input.parse()?;
+            input.parse::<Token![for]>()?;
+            let key: LitStr = input.parse()?;
+            let modifiers = parse_modifiers(input)?;
+            input.parse::<Token![;]>()?;
+            fields.push(FieldDef { ident, ty, key, modifiers });
+        }
+
+        Ok(ComponentDef {
+            name,
+            component_key,
+            component_modifiers,
+            fields,
+        })
+    }
+}
+
+/// Pulls the `(major, minor, patch)` out of a `min_version(1, 19, 70)`
+/// component modifier.
+fn parse_min_version(modifier: &str) -> Option<(i64, i64, i64)> {
+    let inner = modifier.strip_prefix("min_version(")?.strip_suffix(')')?;
+    let mut parts = inner.split(',').map(|part| part.trim().parse::<i64>());
+    Some((parts.next()?.ok()?, parts.next()?.ok()?, parts.next()?.ok()?))
+}
+
+pub(crate) fn expand(def: ComponentDef, prefix: &str, trait_path: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let struct_name = format_ident!("{prefix}{}Component", def.name);
+    let component_key = &def.component_key;
+    let transparent = def.component_modifiers.iter().any(|modifier| modifier == "transparency");
+    let min_version = def.component_modifiers.iter().find_map(|modifier| parse_min_version(modifier));
+
+    let field_idents: Vec<&Ident> = def.fields.iter().map(|field| &field.ident).collect();
+    let field_types: Vec<proc_macro2::TokenStream> = def
+        .fields
+        .iter()
+        .map(|field| {
+            let ty = &field.ty;
+            if field.modifiers.iter().any(|modifier| modifier == "optional") {
+                quote! { Option<#ty> }
+            } else {
+                quote! { #ty }
+            }
+        })
+        .collect();
+
+    let serialize_body = if transparent {
+        let field = &def.fields[0].ident;
+        quote! {
+            format!(
+                "\"{}\": {}",
+                #component_key,
+                ::serde_json::to_string(&self.#field).expect("component value should always serialize"),
+            )
+        }
+    } else {
+        let entries = def.fields.iter().map(|field| {
+            let ident = &field.ident;
+            let key = &field.key;
+            if field.modifiers.iter().any(|modifier| modifier == "optional") {
+                quote! {
+                    self.#ident.as_ref().map(|value| format!(
+                        "\"{}\": {}",
+                        #key,
+                        ::serde_json::to_string(value).expect("field should always serialize"),
+                    ))
+                }
+            } else {
+                quote! {
+                    Some(format!(
+                        "\"{}\": {}",
+                        #key,
+                        ::serde_json::to_string(&self.#ident).expect("field should always serialize"),
+                    ))
+                }
+            }
+        });
+
+        quote! {
+            let fields: Vec<String> = [#(#entries),*].into_iter().flatten().collect();
+            format!("\"{}\": {{{}}}", #component_key, fields.join(", "))
+        }
+    };
+
+    let min_version_override = min_version.map(|(major, minor, patch)| {
+        quote! {
+            fn min_version(&self) -> crate::vio::SemVer {
+                crate::vio::SemVer::new(#major as i32, #minor as i32, #patch as i32)
+            }
+        }
+    });
+
+    quote! {
+        pub struct #struct_name {
+            #( pub #field_idents: #field_types, )*
+        }
+
+        impl #trait_path for #struct_name {
+            fn serialize(&self) -> String {
+                #serialize_body
+            }
+
+            #min_version_override
+        }
+    }
+}
+
+/// Declares an item component struct and its `ItemComponent` impl from a
+/// small DSL:
+///
+/// ```ignore
+/// item_component! {
+///     name = Damage for "minecraft:damage";
+///     value has i32 for "value" with "public" "optional";
+/// }
+/// ```
+///
+/// The component line may carry a single `with` followed by
+/// `"transparency"` (serialize the lone field directly as the component's
+/// value instead of as a nested object) and/or
+/// `"min_version(major, minor, patch)"` (override
+/// `ItemComponent::min_version`), e.g.
+/// `with "transparency" "min_version(1, 19, 80)"`. Field lines may carry `with "optional"`
+/// to wrap the field in `Option<T>` and skip it when absent. See
+/// `src/item/component.rs` for the full set of components built this way.
+#[proc_macro]
+pub fn item_component(input: TokenStream) -> TokenStream {
+    let def = parse_macro_input!(input as ComponentDef);
+    expand(def, "Item", quote! { crate::item::component::ItemComponent }).into()
+}
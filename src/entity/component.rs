@@ -0,0 +1,77 @@
+use entity_component_macros::entity_component;
+use serde::Serialize;
+
+use crate::vio::Identifier;
+
+pub trait EntityComponent {
+    fn serialize(&self) -> String;
+}
+
+// * Health
+
+entity_component! {
+    name = Health for "minecraft:health";
+    value has i32 for "value" with "public";
+    max has i32 for "max" with "public";
+}
+
+// * Movement
+
+entity_component! {
+    name = Movement for "minecraft:movement";
+    value has f64 for "value" with "public";
+}
+
+// * Physics
+
+entity_component! {
+    name = Physics for "minecraft:physics";
+    has_collision has bool for "has_collision" with "public";
+    has_gravity has bool for "has_gravity" with "public";
+}
+
+// * CollisionBox
+
+entity_component! {
+    name = CollisionBox for "minecraft:collision_box";
+    width has f64 for "width" with "public";
+    height has f64 for "height" with "public";
+}
+
+// * TypeFamily
+
+entity_component! {
+    name = TypeFamily for "minecraft:type_family";
+    family has Vec<String> for "family" with "public";
+}
+
+// * BehaviorFloat
+
+entity_component! {
+    name = BehaviorFloat for "minecraft:behavior.float";
+    priority has i32 for "priority" with "public";
+}
+
+// * BehaviorPanic
+
+entity_component! {
+    name = BehaviorPanic for "minecraft:behavior.panic";
+    priority has i32 for "priority" with "public";
+    speed_multiplier has f64 for "speed_multiplier" with "public";
+}
+
+// * BehaviorMeleeAttack
+
+entity_component! {
+    name = BehaviorMeleeAttack for "minecraft:behavior.melee_attack";
+    priority has i32 for "priority" with "public";
+    speed_multiplier has f64 for "speed_multiplier" with "public";
+    track_target has bool for "track_target" with "public";
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct EntityDescription {
+    pub identifier: Identifier,
+    pub is_spawnable: bool,
+    pub is_summonable: bool,
+}
@@ -0,0 +1,97 @@
+pub mod component;
+
+use crate::entity::component::{EntityComponent, EntityDescription};
+use crate::pack::Pack;
+use crate::vio::Identifier;
+
+/// A custom entity definition, registered through
+/// [`Pack::register_entity`] the same way [`crate::item::Item`] is
+/// registered through `Pack::register_item`.
+pub struct Entity<'a> {
+    pub identifier: Identifier,
+    pub is_spawnable: bool,
+    pub is_summonable: bool,
+    pub components: Vec<&'a dyn EntityComponent>,
+}
+
+impl<'a> Entity<'a> {
+    pub fn serialize(&self, format_version: &str) -> String {
+        let description = EntityDescription {
+            identifier: self.identifier.clone(),
+            is_spawnable: self.is_spawnable,
+            is_summonable: self.is_summonable,
+        };
+
+        let components = self
+            .components
+            .iter()
+            .map(|component| component.serialize())
+            .collect::<Vec<String>>()
+            .join(",\n");
+
+        format!(
+            r#"{{
+  "format_version": "{format_version}",
+  "minecraft:entity": {{
+    "description": {},
+    "components": {{
+{components}
+    }}
+  }}
+}}"#,
+            serde_json::to_string(&description).expect("entity description should always serialize"),
+        )
+    }
+}
+
+impl Pack {
+    /// Registers a custom entity so it is emitted into the behavior pack's
+    /// `entities/` folder on [`Pack::generate`](crate::pack::Pack::generate).
+    pub fn register_entity(&mut self, entity: Entity) {
+        let format_version = self.target_version().render();
+        let rendered = entity.serialize(&format_version);
+        self.entities.push((entity.identifier, rendered));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::component::{EntityHealthComponent, EntityPhysicsComponent, EntityTypeFamilyComponent};
+
+    #[test]
+    fn entity_emits_description_and_components() {
+        let health = EntityHealthComponent { value: 20, max: 20 };
+        let physics = EntityPhysicsComponent {
+            has_collision: true,
+            has_gravity: true,
+        };
+        let type_family = EntityTypeFamilyComponent {
+            family: vec!["mob".to_string(), "golem".to_string()],
+        };
+        let entity = Entity {
+            identifier: Identifier::new("test", "golem"),
+            is_spawnable: true,
+            is_summonable: true,
+            components: vec![&health, &physics, &type_family],
+        };
+
+        let rendered: serde_json::Value = serde_json::from_str(&entity.serialize("1.21.40")).unwrap();
+
+        let description = &rendered["minecraft:entity"]["description"];
+        assert_eq!(description["identifier"], "test:golem");
+        assert_eq!(description["is_spawnable"], true);
+        assert_eq!(description["is_summonable"], true);
+
+        let components = &rendered["minecraft:entity"]["components"];
+        assert!(components["minecraft:health"].is_object());
+        assert_eq!(
+            components["minecraft:physics"],
+            serde_json::json!({ "has_collision": true, "has_gravity": true })
+        );
+        assert_eq!(
+            components["minecraft:type_family"],
+            serde_json::json!({ "family": ["mob", "golem"] })
+        );
+    }
+}
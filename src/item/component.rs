@@ -1,4 +1,4 @@
-use crate::vio::{Buildable, ColorCode, Identifier, RangeDescriptor};
+use crate::vio::{Buildable, ColorCode, Identifier, RangeDescriptor, SemVer};
 use serde::{Serialize};
 use item_component_macros::item_component;
 use crate::block::utils::BlockDestroySpeed;
@@ -6,6 +6,13 @@ use crate::item::utils::{DurabilityThreshold, EnchantableSlot, ItemRarity, ItemR
 
 pub trait ItemComponent {
     fn serialize(&self) -> String;
+
+    /// The earliest format version this component is valid in. Defaults to
+    /// 1.16.0, the format version the crate's oldest supported components
+    /// target; components gated to a later release override this.
+    fn min_version(&self) -> SemVer {
+        SemVer::new(1, 16, 0)
+    }
 }
 
 // * ItemDamageComponent
@@ -133,7 +140,7 @@ item_component! {
 // * Enchantable
 
 item_component! {
-    name = Enchantable for "minecraft:enchantable";
+    name = Enchantable for "minecraft:enchantable" with "min_version(1, 19, 70)";
     value has u8 for "value" with "public";
     slot has EnchantableSlot for "slot" with "public";
 }
@@ -164,7 +171,7 @@ item_component! {
 // * DurabilitySensor
 
 item_component! {
-    name = DurabilitySensor for "minecraft:durability_sensor";
+    name = DurabilitySensor for "minecraft:durability_sensor" with "min_version(1, 20, 60)";
     durability_thresholds has Vec<DurabilityThreshold> for "durability_thresholds" with "public";
 }
 
@@ -200,6 +207,6 @@ item_component! {
 // * Rarity
 
 item_component! {
-    name = Rarity for "minecraft:rarity" with "transparency";
+    name = Rarity for "minecraft:rarity" with "transparency" "min_version(1, 19, 80)";
     rarity has ItemRarity for "minecraft:rarity" with "public";
 }
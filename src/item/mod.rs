@@ -0,0 +1,94 @@
+pub mod component;
+
+use crate::item::component::ItemComponent;
+use crate::logger;
+use crate::pack::Pack;
+#[cfg(test)]
+use crate::vio::SemVer;
+
+/// A custom item definition, built from `item_component!`-generated
+/// components and registered through [`Pack::register_item`].
+pub struct Item<'a> {
+    pub type_id: String,
+    pub texture: String,
+    pub components: Vec<&'a dyn ItemComponent>,
+}
+
+impl<'a> Item<'a> {
+    pub fn render(&self, format_version: &str) -> String {
+        let components = self
+            .components
+            .iter()
+            .map(|component| component.serialize())
+            .collect::<Vec<String>>()
+            .join(",\n");
+
+        format!(
+            r#"{{
+  "format_version": "{format_version}",
+  "minecraft:item": {{
+    "description": {{
+      "identifier": "{}"
+    }},
+    "components": {{
+{components}
+    }}
+  }}
+}}"#,
+            self.type_id,
+        )
+    }
+}
+
+impl Pack {
+    /// Registers a custom item so it is emitted into the behavior pack's
+    /// `items/` folder on [`Pack::generate`](crate::pack::Pack::generate).
+    ///
+    /// Every component's [`ItemComponent::min_version`] is checked against
+    /// the pack's [target version](Pack::set_target_version) at generate
+    /// time, and a warning is logged for any component that isn't actually
+    /// supported by the version the pack is authored for.
+    pub fn register_item(&mut self, item: Item) {
+        let component_versions = item
+            .components
+            .iter()
+            .map(|component| component.min_version())
+            .collect();
+
+        let rendered = item.render(&self.target_version().render());
+        self.items.push((item.type_id, rendered, component_versions));
+    }
+
+    pub(crate) fn warn_outdated_item_components(&self) {
+        for (type_id, _rendered, component_versions) in &self.items {
+            for min_version in component_versions {
+                if *min_version > *self.target_version() {
+                    logger::warn(format!(
+                        "item '{type_id}' registers a component that requires format version {} but the pack targets {}",
+                        min_version.render(),
+                        self.target_version().render(),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::item::component::{ItemDamageComponent, ItemEnchantableComponent};
+    use crate::item::utils::EnchantableSlot;
+
+    #[test]
+    fn min_version_modifier_overrides_the_trait_default() {
+        let enchantable = ItemEnchantableComponent {
+            value: 1,
+            slot: EnchantableSlot::Armor,
+        };
+        assert_eq!(enchantable.min_version(), SemVer::new(1, 19, 70));
+
+        let damage = ItemDamageComponent { value: Some(3) };
+        assert_eq!(damage.min_version(), SemVer::new(1, 16, 0));
+    }
+}
@@ -1,8 +1,13 @@
 pub mod constant;
+pub mod entity;
 pub mod item;
 pub mod logger;
+pub mod loot;
 pub mod pack;
+pub mod particle;
+pub mod recipe;
 pub mod template;
+pub mod vio;
 
 #[cfg(test)]
 mod tests {
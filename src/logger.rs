@@ -0,0 +1,16 @@
+//! Minimal build-time logger used while generating a pack, so problems that
+//! shouldn't abort generation (a component gated to a newer format version
+//! than the pack targets, a skipped file, ...) are still surfaced to the
+//! user.
+
+pub fn info(message: impl AsRef<str>) {
+    println!("[violin] info: {}", message.as_ref());
+}
+
+pub fn warn(message: impl AsRef<str>) {
+    eprintln!("[violin] warn: {}", message.as_ref());
+}
+
+pub fn error(message: impl AsRef<str>) {
+    eprintln!("[violin] error: {}", message.as_ref());
+}
@@ -0,0 +1,249 @@
+use serde::Serialize;
+
+use crate::pack::Pack;
+use crate::vio::{Identifier, RangeDescriptor};
+
+/// A loot function applied to a matched [`LootEntry`], such as randomizing
+/// the stack count or block data of the item it produces.
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "function", rename_all = "snake_case")]
+pub enum LootFunction {
+    SetCount { count: RangeDescriptor<i32> },
+    SetData { data: i32 },
+}
+
+/// A single weighted drop within a [`LootPool`].
+#[derive(Clone, Debug, Serialize)]
+pub struct LootEntry {
+    #[serde(rename = "type")]
+    pub entry_type: String,
+    pub name: Identifier,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub functions: Vec<LootFunction>,
+}
+
+impl LootEntry {
+    pub fn item(name: Identifier) -> Self {
+        Self {
+            entry_type: "item".to_string(),
+            name,
+            functions: Vec::new(),
+        }
+    }
+
+    pub fn set_count(mut self, count: RangeDescriptor<i32>) -> Self {
+        self.functions.push(LootFunction::SetCount { count });
+        self
+    }
+
+    pub fn set_data(mut self, data: i32) -> Self {
+        self.functions.push(LootFunction::SetData { data });
+        self
+    }
+}
+
+/// A condition gating a [`LootPool`], such as `killed_by_player`,
+/// serialized as `{"condition": "killed_by_player"}`. A [`LootEntry`]
+/// carries no conditions of its own.
+#[derive(Clone, Debug, Serialize)]
+pub struct LootCondition {
+    pub condition: String,
+}
+
+impl LootCondition {
+    pub fn new(condition: impl Into<String>) -> Self {
+        Self {
+            condition: condition.into(),
+        }
+    }
+}
+
+/// A roll of one or more [`LootEntry`] values, optionally gated by
+/// [`LootCondition`]s such as `killed_by_player`.
+#[derive(Clone, Debug, Serialize)]
+pub struct LootPool {
+    pub rolls: RangeDescriptor<i32>,
+    pub entries: Vec<LootEntry>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub conditions: Vec<LootCondition>,
+}
+
+impl LootPool {
+    pub fn new(rolls: RangeDescriptor<i32>) -> Self {
+        Self {
+            rolls,
+            entries: Vec::new(),
+            conditions: Vec::new(),
+        }
+    }
+
+    pub fn entry(mut self, entry: LootEntry) -> Self {
+        self.entries.push(entry);
+        self
+    }
+
+    pub fn condition(mut self, condition: impl Into<String>) -> Self {
+        self.conditions.push(LootCondition::new(condition));
+        self
+    }
+}
+
+/// A Bedrock loot table, built from [`LootPool`]s and registered through
+/// [`Pack::register_loot_table`].
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct LootTable {
+    pub pools: Vec<LootPool>,
+}
+
+impl LootTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn pool(mut self, pool: LootPool) -> Self {
+        self.pools.push(pool);
+        self
+    }
+
+    pub fn render(&self) -> String {
+        serde_json::to_string_pretty(self).expect("loot table should always serialize")
+    }
+}
+
+/// What a villager wants or gives in a single [`TradeEntry`].
+#[derive(Clone, Debug, Serialize)]
+pub struct TradeItemStack {
+    pub item: Identifier,
+    pub quantity: i32,
+}
+
+impl TradeItemStack {
+    pub fn new(item: Identifier, quantity: i32) -> Self {
+        Self { item, quantity }
+    }
+}
+
+/// A single villager trade within a [`TradeTier`].
+#[derive(Clone, Debug, Serialize)]
+pub struct TradeEntry {
+    pub wants: Vec<TradeItemStack>,
+    pub gives: Vec<TradeItemStack>,
+}
+
+impl TradeEntry {
+    pub fn new(wants: Vec<TradeItemStack>, gives: Vec<TradeItemStack>) -> Self {
+        Self { wants, gives }
+    }
+}
+
+/// A tier of trades unlocked once the villager reaches `total_exp_required`.
+#[derive(Clone, Debug, Serialize)]
+pub struct TradeTier {
+    pub total_exp_required: i32,
+    pub trades: Vec<TradeEntry>,
+}
+
+impl TradeTier {
+    pub fn new(total_exp_required: i32, trades: Vec<TradeEntry>) -> Self {
+        Self {
+            total_exp_required,
+            trades,
+        }
+    }
+}
+
+/// A Bedrock trade table, built from [`TradeTier`]s and registered through
+/// [`Pack::register_trade_table`].
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct TradeTable {
+    pub tiers: Vec<TradeTier>,
+}
+
+impl TradeTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn tier(mut self, tier: TradeTier) -> Self {
+        self.tiers.push(tier);
+        self
+    }
+
+    pub fn render(&self) -> String {
+        serde_json::to_string_pretty(self).expect("trade table should always serialize")
+    }
+}
+
+impl Pack {
+    /// Registers a loot table so it is emitted into the behavior pack's
+    /// `loot_tables/` folder on [`Pack::generate`](crate::pack::Pack::generate).
+    pub fn register_loot_table(&mut self, identifier: Identifier, table: LootTable) {
+        self.loot_tables.push((identifier, table.render()));
+    }
+
+    /// Registers a trade table so it is emitted into the behavior pack's
+    /// `trading/` folder on [`Pack::generate`](crate::pack::Pack::generate).
+    pub fn register_trade_table(&mut self, identifier: Identifier, table: TradeTable) {
+        self.trade_tables.push((identifier, table.render()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loot_table_emits_condition_objects_and_functions() {
+        let table = LootTable::new().pool(
+            LootPool::new(RangeDescriptor::new(1, 1))
+                .entry(
+                    LootEntry::item(Identifier::new("minecraft", "apple"))
+                        .set_count(RangeDescriptor::new(1, 3)),
+                )
+                .condition("killed_by_player"),
+        );
+
+        let rendered: serde_json::Value = serde_json::from_str(&table.render()).unwrap();
+
+        assert_eq!(
+            rendered,
+            serde_json::json!({
+                "pools": [{
+                    "rolls": { "min": 1, "max": 1 },
+                    "entries": [{
+                        "type": "item",
+                        "name": "minecraft:apple",
+                        "functions": [{ "function": "set_count", "count": { "min": 1, "max": 3 } }],
+                    }],
+                    "conditions": [{ "condition": "killed_by_player" }],
+                }]
+            })
+        );
+    }
+
+    #[test]
+    fn trade_table_emits_wants_and_gives() {
+        let table = TradeTable::new().tier(TradeTier::new(
+            0,
+            vec![TradeEntry::new(
+                vec![TradeItemStack::new(Identifier::new("minecraft", "emerald"), 1)],
+                vec![TradeItemStack::new(Identifier::new("minecraft", "bread"), 3)],
+            )],
+        ));
+
+        let rendered: serde_json::Value = serde_json::from_str(&table.render()).unwrap();
+
+        assert_eq!(
+            rendered,
+            serde_json::json!({
+                "tiers": [{
+                    "total_exp_required": 0,
+                    "trades": [{
+                        "wants": [{ "item": "minecraft:emerald", "quantity": 1 }],
+                        "gives": [{ "item": "minecraft:bread", "quantity": 3 }],
+                    }],
+                }]
+            })
+        );
+    }
+}
@@ -0,0 +1,240 @@
+use std::fs;
+use std::path::PathBuf;
+
+use crate::vio::{Identifier, SemVer};
+
+pub struct ScriptData<'a> {
+    pub mc_server_ui_version: String,
+    pub mc_server_version: String,
+    pub paired_scripts_folder: &'a str,
+}
+
+/// A Bedrock add-on pack. Subsystems (items, recipes, entities, loot and
+/// trade tables, particles, ...) register their content onto a `Pack`
+/// through their own `register_*` method, and [`Pack::generate`] renders
+/// everything that has been registered so far into the pack's build
+/// directory.
+pub struct Pack {
+    name: String,
+    identifier: String,
+    author: String,
+    version: String,
+    description: String,
+    has_scripts: bool,
+    behavior_pack_dev_path: PathBuf,
+    resource_pack_dev_path: PathBuf,
+    icon_path: PathBuf,
+
+    target_version: SemVer,
+
+    pub(crate) items: Vec<(String, String, Vec<SemVer>)>,
+    pub(crate) recipes: Vec<crate::recipe::Recipe>,
+    pub(crate) entities: Vec<(Identifier, String)>,
+    pub(crate) loot_tables: Vec<(Identifier, String)>,
+    pub(crate) trade_tables: Vec<(Identifier, String)>,
+    pub(crate) particles: Vec<String>,
+}
+
+impl Pack {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        name: String,
+        identifier: String,
+        author: String,
+        version: &str,
+        description: String,
+        has_scripts: bool,
+        behavior_pack_dev_path: impl Into<PathBuf>,
+        resource_pack_dev_path: impl Into<PathBuf>,
+        icon_path: impl Into<PathBuf>,
+        _scripts: &Option<ScriptData>,
+    ) -> Self {
+        Self {
+            name,
+            identifier,
+            author,
+            version: version.to_string(),
+            description,
+            has_scripts,
+            behavior_pack_dev_path: behavior_pack_dev_path.into(),
+            resource_pack_dev_path: resource_pack_dev_path.into(),
+            icon_path: icon_path.into(),
+            target_version: SemVer::current(),
+            items: Vec::new(),
+            recipes: Vec::new(),
+            entities: Vec::new(),
+            loot_tables: Vec::new(),
+            trade_tables: Vec::new(),
+            particles: Vec::new(),
+        }
+    }
+
+    /// The Bedrock format version this pack targets. Every subsystem
+    /// renders its `format_version` field against this, and
+    /// [`Pack::generate`] warns about any registered component whose
+    /// `min_version` exceeds it.
+    pub fn target_version(&self) -> &SemVer {
+        &self.target_version
+    }
+
+    /// Sets the Bedrock format version this pack targets. Defaults to
+    /// [`SemVer::current`].
+    pub fn set_target_version(&mut self, target_version: SemVer) {
+        self.target_version = target_version;
+    }
+
+    fn behavior_pack_root(&self) -> PathBuf {
+        PathBuf::from("build").join("behavior_packs").join(&self.identifier)
+    }
+
+    fn resource_pack_root(&self) -> PathBuf {
+        PathBuf::from("build").join("resource_packs").join(&self.identifier)
+    }
+
+    fn manifest(&self) -> String {
+        serde_json::json!({
+            "format_version": 2,
+            "header": {
+                "name": self.name,
+                "description": self.description,
+                "uuid": self.identifier,
+                "version": self.version,
+            },
+            "metadata": {
+                "authors": [self.author],
+            },
+            "modules": [{
+                "type": if self.has_scripts { "data" } else { "resources" },
+                "uuid": format!("{}_module", self.identifier),
+                "version": self.version,
+            }],
+        })
+        .to_string()
+    }
+
+    fn write(path: PathBuf, contents: &str) {
+        if let Some(parent) = path.parent() {
+            if let Err(error) = fs::create_dir_all(parent) {
+                crate::logger::error(format!("failed to create {}: {error}", parent.display()));
+                return;
+            }
+        }
+
+        if let Err(error) = fs::write(&path, contents) {
+            crate::logger::error(format!("failed to write {}: {error}", path.display()));
+        }
+    }
+
+    /// Renders every subsystem registered on this pack into its build
+    /// directory, warning about any item component that isn't actually
+    /// supported by [`Pack::target_version`].
+    pub fn generate(&self, verbose: Option<bool>) {
+        let verbose = verbose.unwrap_or(false);
+        self.warn_outdated_item_components();
+
+        let bp_root = self.behavior_pack_root();
+        let rp_root = self.resource_pack_root();
+
+        let manifest = self.manifest();
+        Self::write(bp_root.join("manifest.json"), &manifest);
+        Self::write(rp_root.join("manifest.json"), &manifest);
+
+        if self.icon_path.exists() {
+            if let Err(error) = fs::copy(&self.icon_path, bp_root.join("pack_icon.png")) {
+                crate::logger::error(format!("failed to copy pack icon: {error}"));
+            }
+        }
+
+        for (type_id, rendered, _component_versions) in &self.items {
+            let file_name = type_id.replace(':', "_");
+            Self::write(bp_root.join("items").join(format!("{file_name}.json")), rendered);
+            if verbose {
+                crate::logger::info(format!("generated item {type_id}"));
+            }
+        }
+
+        for recipe in &self.recipes {
+            let identifier = recipe.identifier();
+            let rendered = recipe.serialize(&self.target_version.render());
+            Self::write(
+                bp_root.join("recipes").join(format!("{}.json", identifier.render().replace(':', "_"))),
+                &rendered,
+            );
+            if verbose {
+                crate::logger::info(format!("generated recipe {}", identifier.render()));
+            }
+        }
+
+        for (identifier, rendered) in &self.entities {
+            Self::write(
+                bp_root.join("entities").join(format!("{}.json", identifier.render().replace(':', "_"))),
+                rendered,
+            );
+            if verbose {
+                crate::logger::info(format!("generated entity {}", identifier.render()));
+            }
+        }
+
+        for (identifier, rendered) in &self.loot_tables {
+            Self::write(
+                bp_root.join("loot_tables").join(format!("{}.json", identifier.render().replace(':', "_"))),
+                rendered,
+            );
+            if verbose {
+                crate::logger::info(format!("generated loot table {}", identifier.render()));
+            }
+        }
+
+        for (identifier, rendered) in &self.trade_tables {
+            Self::write(
+                bp_root.join("trading").join(format!("{}.json", identifier.render().replace(':', "_"))),
+                rendered,
+            );
+            if verbose {
+                crate::logger::info(format!("generated trade table {}", identifier.render()));
+            }
+        }
+
+        for (index, rendered) in self.particles.iter().enumerate() {
+            Self::write(rp_root.join("particles").join(format!("particle_{index}.json")), rendered);
+            if verbose {
+                crate::logger::info(format!("generated particle {index}"));
+            }
+        }
+    }
+
+    /// Copies the generated behavior and resource packs into the
+    /// development pack folders so they show up in-game without a
+    /// manual export.
+    pub fn build_to_dev(&self) {
+        let bp_dest = self.behavior_pack_dev_path.join(&self.identifier);
+        let rp_dest = self.resource_pack_dev_path.join(&self.identifier);
+
+        if let Err(error) = copy_dir(&self.behavior_pack_root(), &bp_dest) {
+            crate::logger::error(format!("failed to copy behavior pack to {}: {error}", bp_dest.display()));
+        }
+
+        if let Err(error) = copy_dir(&self.resource_pack_root(), &rp_dest) {
+            crate::logger::error(format!("failed to copy resource pack to {}: {error}", rp_dest.display()));
+        }
+    }
+}
+
+fn copy_dir(src: &std::path::Path, dest: &std::path::Path) -> std::io::Result<()> {
+    if !src.exists() {
+        return Ok(());
+    }
+
+    fs::create_dir_all(dest)?;
+    for entry in fs::read_dir(src)? {
+        let entry = entry?;
+        let dest_path = dest.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &dest_path)?;
+        } else {
+            fs::copy(entry.path(), dest_path)?;
+        }
+    }
+
+    Ok(())
+}
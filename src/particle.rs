@@ -0,0 +1,190 @@
+use serde_json::{json, Map, Value};
+
+use crate::pack::Pack;
+use crate::vio::{Identifier, MolangStatement, Vec3};
+
+/// Builds Bedrock `particle_effect` JSON for the resource pack. Components
+/// are added one at a time through the builder methods below, mirroring
+/// the component groups Bedrock itself recognizes
+/// (`minecraft:emitter_rate_steady`, `minecraft:particle_motion_dynamic`,
+/// ...), and registered through [`Pack::register_particle`].
+pub struct ParticleEffect {
+    identifier: Identifier,
+    texture: String,
+    components: Map<String, Value>,
+}
+
+impl ParticleEffect {
+    pub fn new(identifier: Identifier, texture: impl Into<String>) -> Self {
+        Self {
+            identifier,
+            texture: texture.into(),
+            components: Map::new(),
+        }
+    }
+
+    pub fn emitter_rate_steady(mut self, rate: f64, max_particles: i32) -> Self {
+        self.components.insert(
+            "minecraft:emitter_rate_steady".to_string(),
+            json!({ "rate": rate, "max_particles": max_particles }),
+        );
+        self
+    }
+
+    pub fn emitter_rate_instant(mut self, num_particles: i32) -> Self {
+        self.components.insert(
+            "minecraft:emitter_rate_instant".to_string(),
+            json!({ "num_particles": num_particles }),
+        );
+        self
+    }
+
+    pub fn emitter_lifetime_once(mut self, active_time: f64) -> Self {
+        self.components.insert(
+            "minecraft:emitter_lifetime_once".to_string(),
+            json!({ "active_time": active_time }),
+        );
+        self
+    }
+
+    pub fn emitter_lifetime_looping(mut self, active_time: f64, sleep_time: f64) -> Self {
+        self.components.insert(
+            "minecraft:emitter_lifetime_looping".to_string(),
+            json!({ "active_time": active_time, "sleep_time": sleep_time }),
+        );
+        self
+    }
+
+    pub fn particle_lifetime_expression(mut self, max_lifetime: MolangStatement) -> Self {
+        self.components.insert(
+            "minecraft:particle_lifetime_expression".to_string(),
+            json!({ "max_lifetime": max_lifetime }),
+        );
+        self
+    }
+
+    pub fn particle_initial_speed(mut self, speed: f64) -> Self {
+        self.components
+            .insert("minecraft:particle_initial_speed".to_string(), json!(speed));
+        self
+    }
+
+    pub fn particle_motion_dynamic(mut self, linear_acceleration: Vec3) -> Self {
+        self.components.insert(
+            "minecraft:particle_motion_dynamic".to_string(),
+            json!({
+                "linear_acceleration": [linear_acceleration.x, linear_acceleration.y, linear_acceleration.z],
+            }),
+        );
+        self
+    }
+
+    pub fn particle_appearance_billboard(mut self, size: (f64, f64), uv: (f64, f64), uv_size: (f64, f64)) -> Self {
+        self.components.insert(
+            "minecraft:particle_appearance_billboard".to_string(),
+            json!({
+                "size": [size.0, size.1],
+                "facing_camera_mode": "lookat_xyz",
+                "uv": {
+                    "texture_width": 128,
+                    "texture_height": 128,
+                    "uv": [uv.0, uv.1],
+                    "uv_size": [uv_size.0, uv_size.1],
+                },
+            }),
+        );
+        self
+    }
+
+    pub fn particle_appearance_tinting(mut self, rgba: (f64, f64, f64, f64)) -> Self {
+        self.components.insert(
+            "minecraft:particle_appearance_tinting".to_string(),
+            json!({ "color": { "rgba": [rgba.0, rgba.1, rgba.2, rgba.3] } }),
+        );
+        self
+    }
+
+    pub fn render(&self, format_version: &str) -> String {
+        let document = json!({
+            "format_version": format_version,
+            "particle_effect": {
+                "description": {
+                    "identifier": self.identifier.render(),
+                    "basic_render_parameters": {
+                        "texture": self.texture,
+                        "material": "particles_alpha",
+                    },
+                },
+                "components": self.components,
+            },
+        });
+
+        serde_json::to_string_pretty(&document).expect("particle effect should always serialize")
+    }
+}
+
+impl Pack {
+    /// Registers a particle effect so it is emitted into the resource
+    /// pack's `particles/` folder on [`Pack::generate`](crate::pack::Pack::generate).
+    pub fn register_particle(&mut self, particle: ParticleEffect) {
+        let format_version = self.target_version().render();
+        self.particles.push(particle.render(&format_version));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn particle_effect_emits_requested_components() {
+        let particle = ParticleEffect::new(Identifier::new("test", "spark"), "textures/particle/spark")
+            .emitter_rate_steady(10.0, 50)
+            .emitter_lifetime_looping(1.0, 0.5)
+            .particle_lifetime_expression(MolangStatement::new("variable.particle_age"))
+            .particle_initial_speed(2.0)
+            .particle_motion_dynamic(Vec3 { x: 0.0, y: -1.0, z: 0.0 })
+            .particle_appearance_billboard((1.0, 1.0), (0.0, 0.0), (8.0, 8.0))
+            .particle_appearance_tinting((1.0, 1.0, 1.0, 1.0));
+
+        let rendered: serde_json::Value = serde_json::from_str(&particle.render("1.21.40")).unwrap();
+
+        assert_eq!(
+            rendered["format_version"],
+            serde_json::json!("1.21.40")
+        );
+        assert_eq!(
+            rendered["particle_effect"]["description"],
+            serde_json::json!({
+                "identifier": "test:spark",
+                "basic_render_parameters": {
+                    "texture": "textures/particle/spark",
+                    "material": "particles_alpha",
+                },
+            })
+        );
+
+        let components = &rendered["particle_effect"]["components"];
+        assert_eq!(
+            components["minecraft:emitter_rate_steady"],
+            serde_json::json!({ "rate": 10.0, "max_particles": 50 })
+        );
+        assert_eq!(
+            components["minecraft:emitter_lifetime_looping"],
+            serde_json::json!({ "active_time": 1.0, "sleep_time": 0.5 })
+        );
+        assert_eq!(
+            components["minecraft:particle_lifetime_expression"],
+            serde_json::json!({ "max_lifetime": "variable.particle_age" })
+        );
+        assert_eq!(components["minecraft:particle_initial_speed"], serde_json::json!(2.0));
+        assert_eq!(
+            components["minecraft:particle_motion_dynamic"],
+            serde_json::json!({ "linear_acceleration": [0.0, -1.0, 0.0] })
+        );
+        assert_eq!(
+            components["minecraft:particle_appearance_tinting"],
+            serde_json::json!({ "color": { "rgba": [1.0, 1.0, 1.0, 1.0] } })
+        );
+    }
+}
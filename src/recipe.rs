@@ -0,0 +1,311 @@
+use std::collections::HashMap;
+
+use serde::{Serialize, Serializer};
+
+use crate::pack::Pack;
+use crate::vio::Identifier;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct RecipeDescription {
+    pub identifier: Identifier,
+}
+
+impl RecipeDescription {
+    pub fn new(identifier: Identifier) -> Self {
+        Self { identifier }
+    }
+}
+
+/// A Bedrock item descriptor, e.g. `{"item": "minecraft:stick"}`.
+#[derive(Serialize)]
+struct ItemDescriptor<'a> {
+    item: &'a Identifier,
+}
+
+/// A Bedrock item descriptor with a count, e.g.
+/// `{"item": "minecraft:stick", "count": 4}`.
+#[derive(Serialize)]
+struct ItemCountDescriptor<'a> {
+    item: &'a Identifier,
+    count: u32,
+}
+
+fn serialize_result<S>(result: &(Identifier, u32), serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    ItemCountDescriptor {
+        item: &result.0,
+        count: result.1,
+    }
+    .serialize(serializer)
+}
+
+fn serialize_ingredients<S>(ingredients: &[Identifier], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    ingredients
+        .iter()
+        .map(|item| ItemDescriptor { item })
+        .collect::<Vec<_>>()
+        .serialize(serializer)
+}
+
+fn serialize_key<S>(key: &HashMap<char, Identifier>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    use serde::ser::SerializeMap;
+
+    let mut map = serializer.serialize_map(Some(key.len()))?;
+    for (symbol, item) in key {
+        map.serialize_entry(&symbol.to_string(), &ItemDescriptor { item })?;
+    }
+    map.end()
+}
+
+/// A `minecraft:recipe_shaped` entry — crafts `result` from ingredients
+/// arranged in `pattern`, where each character in the pattern is resolved
+/// through `key`.
+#[derive(Clone, Debug, Serialize)]
+pub struct RecipeShaped {
+    pub description: RecipeDescription,
+    pub tags: Vec<String>,
+    pub pattern: Vec<String>,
+    #[serde(serialize_with = "serialize_key")]
+    pub key: HashMap<char, Identifier>,
+    #[serde(serialize_with = "serialize_result")]
+    pub result: (Identifier, u32),
+}
+
+impl RecipeShaped {
+    pub fn new(
+        identifier: Identifier,
+        pattern: Vec<String>,
+        key: HashMap<char, Identifier>,
+        result: (Identifier, u32),
+    ) -> Self {
+        Self {
+            description: RecipeDescription::new(identifier),
+            tags: vec!["crafting_table".to_string()],
+            pattern,
+            key,
+            result,
+        }
+    }
+}
+
+/// A `minecraft:recipe_shapeless` entry — crafts `result` from any
+/// arrangement of `ingredients`.
+#[derive(Clone, Debug, Serialize)]
+pub struct RecipeShapeless {
+    pub description: RecipeDescription,
+    pub tags: Vec<String>,
+    #[serde(serialize_with = "serialize_ingredients")]
+    pub ingredients: Vec<Identifier>,
+    #[serde(serialize_with = "serialize_result")]
+    pub result: (Identifier, u32),
+}
+
+impl RecipeShapeless {
+    pub fn new(identifier: Identifier, ingredients: Vec<Identifier>, result: (Identifier, u32)) -> Self {
+        Self {
+            description: RecipeDescription::new(identifier),
+            tags: vec!["crafting_table".to_string()],
+            ingredients,
+            result,
+        }
+    }
+}
+
+/// A `minecraft:recipe_furnace` entry — smelts `input` into `output`.
+#[derive(Clone, Debug, Serialize)]
+pub struct RecipeFurnace {
+    pub description: RecipeDescription,
+    pub tags: Vec<String>,
+    pub input: Identifier,
+    pub output: Identifier,
+}
+
+impl RecipeFurnace {
+    pub fn new(identifier: Identifier, input: Identifier, output: Identifier, tags: Vec<String>) -> Self {
+        Self {
+            description: RecipeDescription::new(identifier),
+            tags,
+            input,
+            output,
+        }
+    }
+}
+
+/// A `minecraft:recipe_brewing_mix` entry — brews `input` with
+/// `reagent` into `output`.
+#[derive(Clone, Debug, Serialize)]
+pub struct RecipeBrewing {
+    pub description: RecipeDescription,
+    pub tags: Vec<String>,
+    pub input: Identifier,
+    pub reagent: Identifier,
+    pub output: Identifier,
+}
+
+impl RecipeBrewing {
+    pub fn new(identifier: Identifier, input: Identifier, reagent: Identifier, output: Identifier) -> Self {
+        Self {
+            description: RecipeDescription::new(identifier),
+            tags: vec!["brewing_stand".to_string()],
+            input,
+            reagent,
+            output,
+        }
+    }
+}
+
+/// A `minecraft:recipe_smithing_transform` entry — combines `template`,
+/// `base`, and `addition` into `result`.
+#[derive(Clone, Debug, Serialize)]
+pub struct RecipeSmithingTransform {
+    pub description: RecipeDescription,
+    pub tags: Vec<String>,
+    pub template: Identifier,
+    pub base: Identifier,
+    pub addition: Identifier,
+    pub result: Identifier,
+}
+
+impl RecipeSmithingTransform {
+    pub fn new(
+        identifier: Identifier,
+        template: Identifier,
+        base: Identifier,
+        addition: Identifier,
+        result: Identifier,
+    ) -> Self {
+        Self {
+            description: RecipeDescription::new(identifier),
+            tags: vec!["smithing_table".to_string()],
+            template,
+            base,
+            addition,
+            result,
+        }
+    }
+}
+
+pub enum Recipe {
+    Shaped(RecipeShaped),
+    Shapeless(RecipeShapeless),
+    Furnace(RecipeFurnace),
+    Brewing(RecipeBrewing),
+    SmithingTransform(RecipeSmithingTransform),
+}
+
+impl Recipe {
+    /// Returns the `minecraft:recipe_*` key this recipe serializes under.
+    fn component_key(&self) -> &'static str {
+        match self {
+            Recipe::Shaped(_) => "minecraft:recipe_shaped",
+            Recipe::Shapeless(_) => "minecraft:recipe_shapeless",
+            Recipe::Furnace(_) => "minecraft:recipe_furnace",
+            Recipe::Brewing(_) => "minecraft:recipe_brewing_mix",
+            Recipe::SmithingTransform(_) => "minecraft:recipe_smithing_transform",
+        }
+    }
+
+    pub fn identifier(&self) -> &Identifier {
+        match self {
+            Recipe::Shaped(recipe) => &recipe.description.identifier,
+            Recipe::Shapeless(recipe) => &recipe.description.identifier,
+            Recipe::Furnace(recipe) => &recipe.description.identifier,
+            Recipe::Brewing(recipe) => &recipe.description.identifier,
+            Recipe::SmithingTransform(recipe) => &recipe.description.identifier,
+        }
+    }
+
+    pub fn serialize(&self, format_version: &str) -> String {
+        let body = match self {
+            Recipe::Shaped(recipe) => serde_json::to_value(recipe),
+            Recipe::Shapeless(recipe) => serde_json::to_value(recipe),
+            Recipe::Furnace(recipe) => serde_json::to_value(recipe),
+            Recipe::Brewing(recipe) => serde_json::to_value(recipe),
+            Recipe::SmithingTransform(recipe) => serde_json::to_value(recipe),
+        }
+        .expect("recipe should always serialize");
+
+        let mut document = serde_json::Map::new();
+        document.insert("format_version".to_string(), serde_json::json!(format_version));
+        document.insert(self.component_key().to_string(), body);
+
+        serde_json::to_string_pretty(&serde_json::Value::Object(document))
+            .expect("recipe document should always serialize")
+    }
+}
+
+impl Pack {
+    /// Registers a recipe so it is emitted into the behavior pack's
+    /// `recipes/` folder on [`Pack::generate`](crate::pack::Pack::generate).
+    pub fn register_recipe(&mut self, recipe: Recipe) {
+        self.recipes.push(recipe);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shaped_recipe_emits_item_descriptors() {
+        let recipe = Recipe::Shaped(RecipeShaped::new(
+            Identifier::new("test", "stick_sword"),
+            vec!["#".to_string()],
+            HashMap::from([('#', Identifier::new("minecraft", "stick"))]),
+            (Identifier::new("test", "sword"), 1),
+        ));
+
+        let rendered: serde_json::Value =
+            serde_json::from_str(&recipe.serialize("1.21.40")).unwrap();
+
+        assert_eq!(
+            rendered,
+            serde_json::json!({
+                "format_version": "1.21.40",
+                "minecraft:recipe_shaped": {
+                    "description": { "identifier": "test:stick_sword" },
+                    "tags": ["crafting_table"],
+                    "pattern": ["#"],
+                    "key": { "#": { "item": "minecraft:stick" } },
+                    "result": { "item": "test:sword", "count": 1 },
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn shapeless_recipe_emits_item_descriptors() {
+        let recipe = Recipe::Shapeless(RecipeShapeless::new(
+            Identifier::new("test", "mash"),
+            vec![Identifier::new("minecraft", "apple"), Identifier::new("minecraft", "carrot")],
+            (Identifier::new("test", "mash"), 2),
+        ));
+
+        let rendered: serde_json::Value =
+            serde_json::from_str(&recipe.serialize("1.21.40")).unwrap();
+
+        assert_eq!(
+            rendered,
+            serde_json::json!({
+                "format_version": "1.21.40",
+                "minecraft:recipe_shapeless": {
+                    "description": { "identifier": "test:mash" },
+                    "tags": ["crafting_table"],
+                    "ingredients": [
+                        { "item": "minecraft:apple" },
+                        { "item": "minecraft:carrot" },
+                    ],
+                    "result": { "item": "test:mash", "count": 2 },
+                }
+            })
+        );
+    }
+}
@@ -59,7 +59,7 @@ pub trait Generatable {
     fn generate(&self, path_buf: impl Into<PathBuf>);
 }
 
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct SemVer {
     major: i32,
     minor: i32,
@@ -67,6 +67,23 @@ pub struct SemVer {
     beta: bool,
 }
 
+impl PartialOrd for SemVer {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SemVer {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // `beta` must factor into `cmp` so it agrees with the derived `Eq`
+        // (a.cmp(b) == Equal must imply a == b); a beta release sorts
+        // before its stable counterpart at the same major/minor/patch.
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| other.beta.cmp(&self.beta))
+    }
+}
+
 impl SemVer {
     pub fn new(major: i32, minor: i32, patch: i32) -> Self {
         Self {
@@ -215,4 +232,134 @@ impl ColorCode {
             ColorCode::MaterialResin => "§v",
         }
     }
+}
+
+/// A single segment of a [`RawText`] — either a plain string or a
+/// localized `translate` key with substitution arguments.
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+pub enum RawTextSegment {
+    Text {
+        text: String,
+    },
+    Translate {
+        translate: String,
+        with: Vec<String>,
+    },
+}
+
+/// Builds Bedrock `rawtext` JSON, the format used for item display names,
+/// `InteractButton`, and logger output wherever the client needs to resolve
+/// localization at render time instead of baking a string ahead of time.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct RawText {
+    rawtext: Vec<RawTextSegment>,
+}
+
+impl RawText {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.rawtext.push(RawTextSegment::Text { text: text.into() });
+        self
+    }
+
+    pub fn translate(mut self, key: impl Into<String>, with: Vec<String>) -> Self {
+        self.rawtext.push(RawTextSegment::Translate {
+            translate: key.into(),
+            with,
+        });
+        self
+    }
+
+    pub fn render(&self) -> String {
+        serde_json::to_string(self).expect("rawtext should always serialize")
+    }
+}
+
+/// Fluent `§`-code styling for strings, mirroring the way `rawtext` segments
+/// are built up but for plain chat/sign text that doesn't need JSON.
+pub trait Colorize {
+    fn bold(&self) -> String;
+    fn italic(&self) -> String;
+    fn obfuscated(&self) -> String;
+    fn color(&self, color: ColorCode) -> String;
+}
+
+impl Colorize for str {
+    fn bold(&self) -> String {
+        format!("§l{}§r", self)
+    }
+
+    fn italic(&self) -> String {
+        format!("§o{}§r", self)
+    }
+
+    fn obfuscated(&self) -> String {
+        format!("§k{}§r", self)
+    }
+
+    fn color(&self, color: ColorCode) -> String {
+        format!("{}{}§r", color.str_code(), self)
+    }
+}
+
+impl Colorize for String {
+    fn bold(&self) -> String {
+        self.as_str().bold()
+    }
+
+    fn italic(&self) -> String {
+        self.as_str().italic()
+    }
+
+    fn obfuscated(&self) -> String {
+        self.as_str().obfuscated()
+    }
+
+    fn color(&self, color: ColorCode) -> String {
+        self.as_str().color(color)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn semver_ord_agrees_with_eq() {
+        let beta = SemVer::new_beta(1, 21, 40);
+        let stable = SemVer::new(1, 21, 40);
+
+        assert_ne!(beta, stable);
+        assert_ne!(beta.cmp(&stable), std::cmp::Ordering::Equal);
+        assert!(beta < stable);
+    }
+
+    #[test]
+    fn rawtext_emits_text_and_translate_segments() {
+        let rawtext = RawText::new()
+            .text("Hello, ")
+            .translate("item.name", vec!["%1".to_string()]);
+
+        let rendered: serde_json::Value = serde_json::from_str(&rawtext.render()).unwrap();
+
+        assert_eq!(
+            rendered,
+            serde_json::json!({
+                "rawtext": [
+                    { "text": "Hello, " },
+                    { "translate": "item.name", "with": ["%1"] },
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn colorize_wraps_text_in_format_codes() {
+        assert_eq!("Hello".bold(), "§lHello§r");
+        assert_eq!("Hello".color(ColorCode::Gold), "§6Hello§r");
+    }
 }
\ No newline at end of file